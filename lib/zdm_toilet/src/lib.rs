@@ -1,34 +1,66 @@
 pub mod zdm_toilet {
+    use regex::bytes::Regex as BytesRegex;
     use regex::Regex;
+    use std::collections::HashMap;
+    use std::io::{self, BufRead, Write};
+    use std::sync::OnceLock;
 
-    /// This is the error code returned by Err in do_toiletify_word.
+    /// This is the error code returned by Err in the toiletify functions.
     #[derive(Debug, PartialEq)]
     pub enum Error {
+        /// This error code is returned when the word is empty.
+        EmptyWord,
         /// This error code is returned when the word has a space.
         WordHasSpace,
+        /// This error code is returned when the word is longer than the
+        /// max_word_len in ToiletConfig.
+        WordTooLong,
         /// This error code is returned when the word is not transformed.
         NonToiletWord,
         /// This error code is returned when the regex returns an error.
         InternalRegexError(regex::Error),
     }
 
-    fn do_toiletify_word(word: &str) -> Result<String, regex::Error> {
-        let re_result = Regex::new(r"[Tt][^Tt]+[Ll][^Tt]+[Tt]");
-        let re: Regex;
-
-        match re_result {
-            Ok(r_re) => {
-                re = r_re;
-            }
-            Err(r_error) => {
-                return Err(r_error);
-            }
-        }
+    /// Options for toiletify_word_config.
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct ToiletConfig {
+        /// Words longer than this many bytes return Error::WordTooLong
+        /// instead of being processed. None means there is no limit.
+        pub max_word_len: Option<usize>,
+        /// If true, only lowercase 't', 'l' and 't' are anchors, so twiLight
+        /// is left alone. If false, the anchors match either case.
+        pub case_sensitive_anchors: bool,
+    }
 
-        let new_word = re.replace(word, "toilet").into_owned();
-        Ok(new_word)
+    /// What toiletify_sentence_with does with the whitespace between words.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum WhitespacePolicy {
+        /// Runs of whitespace, including tabs and line breaks, become a
+        /// single space. Leading and trailing whitespace is dropped, and so
+        /// are words left empty by an empty replacement.
+        Collapse,
+        /// All whitespace is kept exactly as it was.
+        Preserve,
     }
 
+    /// The default pattern. Only ASCII 't', 'T', 'l' and 'L' are anchors.
+    const TOILET_PATTERN: &str = r"[Tt][^Tt]+[Ll][^Tt]+[Tt]";
+
+    /// The default pattern with lowercase-only anchors.
+    const CASE_SENSITIVE_TOILET_PATTERN: &str = r"t[^Tt]+l[^Tt]+t";
+
+    /// The default pattern, anchored so it has to cover the whole word.
+    const WHOLE_WORD_TOILET_PATTERN: &str = r"^[Tt][^Tt]+[Ll][^Tt]+[Tt]$";
+
+    /// The default pattern for bytes. Non-ASCII bytes can't be part of a
+    /// match.
+    const BYTES_TOILET_PATTERN: &str = r"(?-u)[Tt][^Tt\x80-\xFF]+[Ll][^Tt\x80-\xFF]+[Tt]";
+
+    /// The Unicode pattern. See [`toiletify_word_unicode`] for the anchor
+    /// letters. The letters between may not be any of the t anchors.
+    const UNICODE_TOILET_PATTERN: &str =
+        r"(?iu)[tţťŧțṫṭṯṱẗƫƭʈ][^tţťŧțṫṭṯṱẗƫƭʈ]+[lĺļľŀłḷḹḻḽƚɫɬɭ][^tţťŧțṫṭṯṱẗƫƭʈ]+[tţťŧțṫṭṯṱẗƫƭʈ]";
+
     /// Transforms a substring of a word into toilet based on certain conditons.
     ///
     /// This string or substring must begin with 't', have some letters (not t!)
@@ -41,31 +73,223 @@ pub mod zdm_toilet {
     /// - totalitarian => totoiletarian
     /// - teletypewriter => toiletypewriter
     ///
+    /// # Unicode
+    /// The letters between the anchors can be any character that is not
+    /// 't' or 'T', so accented letters match there (tîlît => toilet).
+    /// The anchors themselves are ASCII only, so ťwilight is left alone.
+    /// Use [`toiletify_word_unicode`] to also match some accented and
+    /// hooked t's and l's as anchors.
+    ///
+    /// # Idempotency
+    /// Toiletifying a transformed word again never changes it, and returns
+    /// Error::NonToiletWord. "toilet" matches the pattern itself, and any
+    /// match starting before it would also have matched in the original
+    /// word, so the second pass can only replace "toilet" with "toilet".
+    ///
     /// # Arguments
     ///
     /// * 'word' - The word with no spaces.
     ///
     /// # Returns
     /// - String transformed if word meets the conditions above.
+    /// - Error::EmptyWord if the word is empty.
     /// - Error::WordHasSpace if the word contains a space.
     /// - Error::NonToiletWord if the word does not meet the conditions.
     /// - Error::InternalRegexError if the regex fails for some reason.
     ///
     pub fn toiletify_word(word: &str) -> Result<String, Error> {
+        toiletify_word_with(word, "toilet")
+    }
+
+    /// Same as toiletify_word, but with the options in config.
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    /// * 'config' - The options to use.
+    ///
+    /// # Returns
+    /// - String transformed if word meets the conditions of toiletify_word.
+    /// - Error::WordTooLong if the word is longer than config.max_word_len.
+    /// - The same errors as toiletify_word.
+    ///
+    pub fn toiletify_word_config(word: &str, config: &ToiletConfig) -> Result<String, Error> {
+        if let Some(max_word_len) = config.max_word_len {
+            if word.len() > max_word_len {
+                return Err(Error::WordTooLong);
+            }
+        }
+
+        if config.case_sensitive_anchors {
+            toiletify_word_pattern(word, CASE_SENSITIVE_TOILET_PATTERN, "toilet")
+        } else {
+            toiletify_word(word)
+        }
+    }
+
+    /// Same as toiletify_word, but returns an Option instead of an error.
+    ///
+    /// Every error from toiletify_word, including a word with a space,
+    /// becomes None. It never panics, so it fits well in iterator chains.
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    ///
+    /// # Returns
+    /// - Some(String) if the word was transformed.
+    /// - None if the word was not changed for any reason.
+    ///
+    pub fn toiletify_word_opt(word: &str) -> Option<String> {
+        toiletify_word(word).ok()
+    }
+
+    /// Same as toiletify_word, but replaces the matched substring with
+    /// the given replacement instead of "toilet".
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    /// * 'replacement' - The string to put in place of the match.
+    ///
+    /// # Returns
+    /// - String transformed if word meets the conditions of toiletify_word.
+    /// - The same errors as toiletify_word.
+    ///
+    pub fn toiletify_word_with(word: &str, replacement: &str) -> Result<String, Error> {
+        toiletify_word_pattern(word, TOILET_PATTERN, replacement)
+    }
+
+    /// Same as toiletify_word, but only transforms the word when the whole
+    /// word matches the pattern.
+    ///
+    /// # Examples
+    /// - twilight => toilet
+    /// - totalitarian => Error::NonToiletWord (only a substring matches)
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    ///
+    /// # Returns
+    /// - "toilet" if the whole word meets the conditions of toiletify_word.
+    /// - The same errors as toiletify_word.
+    ///
+    pub fn toiletify_whole_word(word: &str) -> Result<String, Error> {
+        toiletify_word_pattern(word, WHOLE_WORD_TOILET_PATTERN, "toilet")
+    }
+
+    /// Same as toiletify_word, but the anchors also match these accented
+    /// and hooked letters, and their uppercase forms:
+    /// - t: tţťŧțṫṭṯṱẗƫƭʈ
+    /// - l: lĺļľŀłḷḹḻḽƚɫɬɭ
+    ///
+    /// Other variants, such as t with a combining accent, do not match.
+    ///
+    /// # Examples
+    /// - ťwilight => toilet
+    /// - ŤWIŁIGHT => toilet
+    /// - ṭwiḷight => toilet
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    ///
+    /// # Returns
+    /// - String transformed if word meets the Unicode conditions.
+    /// - The same errors as toiletify_word.
+    ///
+    pub fn toiletify_word_unicode(word: &str) -> Result<String, Error> {
+        toiletify_with_regex(word, unicode_regex(), "toilet")
+    }
+
+    /// Checks the conditions every word has to meet before the pattern is
+    /// applied.
+    fn check_word(word: &str) -> Result<(), Error> {
+        // Nothing to transform!
+        if word.is_empty() {
+            return Err(Error::EmptyWord);
+        }
+
         // No words with spaces!
         if word.find(' ').is_some() {
             return Err(Error::WordHasSpace);
         }
 
-        let new_word_or_none = do_toiletify_word(&word);
-        let new_word: String;
+        Ok(())
+    }
 
-        match new_word_or_none {
-            Ok(r_new_word) => new_word = r_new_word,
+    fn toiletify_word_pattern(
+        word: &str,
+        pattern: &str,
+        replacement: &str,
+    ) -> Result<String, Error> {
+        let re = match Regex::new(pattern) {
+            Ok(r_re) => r_re,
             Err(r_error) => {
                 return Err(Error::InternalRegexError(r_error));
             }
+        };
+
+        toiletify_with_regex(word, &re, replacement)
+    }
+
+    /// The compiled default pattern, built on first use and shared after
+    /// that. Compiling is far slower than matching, so functions that run
+    /// on many words use this instead of compiling their own.
+    fn default_regex() -> &'static Regex {
+        static DEFAULT_REGEX: OnceLock<Regex> = OnceLock::new();
+        DEFAULT_REGEX.get_or_init(|| Regex::new(TOILET_PATTERN).expect("TOILET_PATTERN is valid"))
+    }
+
+    /// The compiled Unicode pattern, built on first use and shared after that.
+    fn unicode_regex() -> &'static Regex {
+        static UNICODE_REGEX: OnceLock<Regex> = OnceLock::new();
+        UNICODE_REGEX.get_or_init(|| {
+            Regex::new(UNICODE_TOILET_PATTERN).expect("UNICODE_TOILET_PATTERN is valid")
+        })
+    }
+
+    /// Returns the regex toiletify_word uses, so it can be passed to
+    /// toiletify_with_regex or used directly.
+    ///
+    /// # Returns
+    /// - The compiled default pattern.
+    /// - Error::InternalRegexError if the regex fails for some reason.
+    ///
+    pub fn toilet_regex() -> Result<Regex, Error> {
+        match Regex::new(TOILET_PATTERN) {
+            Ok(r_re) => Ok(r_re),
+            Err(r_error) => Err(Error::InternalRegexError(r_error)),
         }
+    }
+
+    /// Same as toiletify_word_with, but matches with the given regex instead
+    /// of the default pattern. Only the first match is replaced.
+    ///
+    /// # Examples
+    /// - "cat" with `[Cc].t` and "dog" => dog
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    /// * 're' - The regex to match with.
+    /// * 'replacement' - The string to put in place of the match.
+    ///
+    /// # Returns
+    /// - String transformed if the regex matched and the word changed.
+    /// - Error::EmptyWord if the word is empty.
+    /// - Error::WordHasSpace if the word contains a space.
+    /// - Error::NonToiletWord if the word was not changed.
+    ///
+    pub fn toiletify_with_regex(
+        word: &str,
+        re: &Regex,
+        replacement: &str,
+    ) -> Result<String, Error> {
+        check_word(word)?;
+
+        let new_word = re.replace(word, replacement).into_owned();
 
         if new_word == *word {
             Err(Error::NonToiletWord)
@@ -74,6 +298,476 @@ pub mod zdm_toilet {
         }
     }
 
+    /// What toiletify_reversible needs to turn a transformed word back into
+    /// the original.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ToiletUndo {
+        /// The substring that was replaced with "toilet".
+        original: String,
+        /// The byte position in the transformed word where "toilet" starts.
+        position: usize,
+    }
+
+    impl ToiletUndo {
+        /// The substring that was replaced with "toilet".
+        pub fn original(&self) -> &str {
+            &self.original
+        }
+
+        /// The byte position in the transformed word where "toilet" starts.
+        /// This is also where the original substring started.
+        pub fn position(&self) -> usize {
+            self.position
+        }
+    }
+
+    /// Same as toiletify_word, but also returns a ToiletUndo so the
+    /// transform can be reversed with undo.
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word with no spaces.
+    ///
+    /// # Returns
+    /// - The transformed word and its ToiletUndo.
+    /// - The same errors as toiletify_word.
+    ///
+    pub fn toiletify_reversible(word: &str) -> Result<(String, ToiletUndo), Error> {
+        check_word(word)?;
+
+        let found = match default_regex().find(word) {
+            Some(r_found) => r_found,
+            None => {
+                return Err(Error::NonToiletWord);
+            }
+        };
+
+        let new_word = format!("{}toilet{}", &word[..found.start()], &word[found.end()..]);
+        if new_word == *word {
+            return Err(Error::NonToiletWord);
+        }
+
+        let undo = ToiletUndo {
+            original: found.as_str().to_owned(),
+            position: found.start(),
+        };
+
+        Ok((new_word, undo))
+    }
+
+    /// Reverses toiletify_reversible.
+    ///
+    /// # Arguments
+    ///
+    /// * 'transformed' - The word returned by toiletify_reversible.
+    /// * 'toilet_undo' - The ToiletUndo returned with it.
+    ///
+    /// # Returns
+    /// - The original word.
+    /// - transformed unchanged if "toilet" is not where toilet_undo says it is.
+    ///
+    pub fn undo(transformed: &str, toilet_undo: &ToiletUndo) -> String {
+        let end = toilet_undo.position + "toilet".len();
+
+        match transformed.get(toilet_undo.position..end) {
+            Some("toilet") => format!(
+                "{}{}{}",
+                &transformed[..toilet_undo.position],
+                toilet_undo.original,
+                &transformed[end..]
+            ),
+            _ => transformed.to_owned(),
+        }
+    }
+
+    /// Counts how many times the toilet pattern matches in a word.
+    ///
+    /// Matches do not overlap. This does not build the transformed word, so
+    /// it is cheaper than toiletify_word when only a count or a yes/no is
+    /// needed.
+    ///
+    /// This is the number of matches, not the number of replacements:
+    /// toiletify_word only replaces the first match, so twilighttwilight
+    /// becomes toilettwilight. A count above 0 also does not mean the word
+    /// will change: toilet counts 1, but toiletify_word returns
+    /// Error::NonToiletWord because it would be replaced with itself.
+    ///
+    /// # Examples
+    /// - twilight => 1
+    /// - twilighttwilight => 2
+    /// - toilet => 1
+    /// - cat => 0
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word to check.
+    ///
+    /// # Returns
+    /// - The number of matches. 0 means toiletify_word would not change it.
+    ///   Anything above 0 means it might.
+    ///
+    pub fn toiletify_count(word: &str) -> usize {
+        default_regex().find_iter(word).count()
+    }
+
+    /// Checks if a word looks like it has already been toiletified.
+    ///
+    /// This is a heuristic. The transform leaves no marker, so a word that
+    /// naturally contains "toilet" can't be told apart from one the transform
+    /// produced, and both return true. That is usually what a pipeline
+    /// wants: "toilet" matches the pattern itself, so running the transform
+    /// on it again would just replace it with itself.
+    ///
+    /// # Examples
+    /// - totoiletarian => true
+    /// - Toilet => true
+    /// - totalitarian => false
+    ///
+    /// # Arguments
+    ///
+    /// * 'word' - The word to check.
+    ///
+    /// # Returns
+    /// - true if the word contains "toilet", ignoring case.
+    ///
+    pub fn is_toiletified(word: &str) -> bool {
+        word.to_lowercase().contains("toilet")
+    }
+
+    /// Toiletifies every word in a corpus and counts the transformations.
+    ///
+    /// Words that do not transform are kept as they are and are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * 'words' - The words to transform.
+    ///
+    /// # Returns
+    /// - The words after toiletify_word, in the same order.
+    /// - A map from each original word that transformed to how many times
+    ///   it was transformed.
+    ///
+    pub fn toiletify_corpus(words: &[&str]) -> (Vec<String>, HashMap<String, usize>) {
+        let mut new_words: Vec<String> = Vec::with_capacity(words.len());
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for word in words {
+            match toiletify_with_regex(word, default_regex(), "toilet") {
+                Ok(new_word) => {
+                    *counts.entry((*word).to_owned()).or_insert(0) += 1;
+                    new_words.push(new_word);
+                }
+                Err(_error) => new_words.push((*word).to_owned()),
+            }
+        }
+
+        (new_words, counts)
+    }
+
+    /// Toiletifies every word in a sentence.
+    ///
+    /// The sentence is split on whitespace and each word is passed through
+    /// toiletify_word. Words that do not transform are kept as they are.
+    /// The words are joined back together with single spaces.
+    ///
+    /// If a word starts with an uppercase letter, the transformed word does
+    /// too, so a match at the start of a capitalized word is capitalized.
+    ///
+    /// # Examples
+    /// - "the twilight zone" => "the toilet zone"
+    /// - "Twilight falls" => "Toilet falls"
+    ///
+    /// # Arguments
+    ///
+    /// * 'sentence' - The sentence to transform.
+    ///
+    pub fn toiletify_sentence(sentence: &str) -> String {
+        toiletify_sentence_with(sentence, "toilet", WhitespacePolicy::Collapse)
+    }
+
+    /// Same as toiletify_sentence, but uses the given replacement instead
+    /// of "toilet" and lets the caller choose what happens to whitespace.
+    ///
+    /// # Examples
+    /// - "a  twilight\tzone" with Collapse => "a toilet zone"
+    /// - "a  twilight\tzone" with Preserve => "a  toilet\tzone"
+    ///
+    /// # Arguments
+    ///
+    /// * 'sentence' - The sentence to transform.
+    /// * 'replacement' - The string to put in place of each match.
+    /// * 'policy' - Whether to collapse or preserve whitespace between words.
+    ///
+    pub fn toiletify_sentence_with(
+        sentence: &str,
+        replacement: &str,
+        policy: WhitespacePolicy,
+    ) -> String {
+        toiletify_sentence_with_regex(sentence, default_regex(), replacement, policy)
+    }
+
+    /// Same as toiletify_sentence_with, but matches with the given regex.
+    ///
+    /// Compiling the regex is far slower than matching it, so callers that
+    /// toiletify many sentences should get it once from toilet_regex and
+    /// pass it in every time.
+    ///
+    /// # Arguments
+    ///
+    /// * 'sentence' - The sentence to transform.
+    /// * 're' - The regex to match with.
+    /// * 'replacement' - The string to put in place of each match.
+    /// * 'policy' - Whether to collapse or preserve whitespace between words.
+    ///
+    pub fn toiletify_sentence_with_regex(
+        sentence: &str,
+        re: &Regex,
+        replacement: &str,
+        policy: WhitespacePolicy,
+    ) -> String {
+        match policy {
+            WhitespacePolicy::Collapse => {
+                let words: Vec<String> = sentence
+                    .split_whitespace()
+                    .map(|word| toiletify_sentence_word(word, re, replacement))
+                    // An empty replacement can leave nothing of a word.
+                    .filter(|word| !word.is_empty())
+                    .collect();
+
+                words.join(" ")
+            }
+            WhitespacePolicy::Preserve => {
+                toiletify_preserving_whitespace(sentence, re, replacement)
+            }
+        }
+    }
+
+    /// Toiletifies a token that may have punctuation around it.
+    ///
+    /// Leading and trailing characters that are not letters or digits are
+    /// stripped, the rest is toiletified like a word in toiletify_sentence,
+    /// and the punctuation is put back.
+    ///
+    /// # Examples
+    /// - "twilight," => "toilet,"
+    /// - "(twilight)" => "(toilet)"
+    ///
+    /// # Arguments
+    ///
+    /// * 'token' - The token with no spaces.
+    ///
+    /// # Returns
+    /// - The transformed token, or the token unchanged if it does not match.
+    ///
+    pub fn toiletify_token(token: &str) -> String {
+        let core_start = match token.find(char::is_alphanumeric) {
+            Some(r_start) => r_start,
+            None => return token.to_owned(),
+        };
+        let core_end = match token.rfind(char::is_alphanumeric) {
+            Some(r_last) => r_last + token[r_last..].chars().next().map_or(0, char::len_utf8),
+            None => return token.to_owned(),
+        };
+
+        format!(
+            "{}{}{}",
+            &token[..core_start],
+            toiletify_sentence_word(&token[core_start..core_end], default_regex(), "toilet"),
+            &token[core_end..]
+        )
+    }
+
+    /// Toiletifies a single word taken from a sentence. Words that do not
+    /// transform are returned unchanged.
+    fn toiletify_sentence_word(word: &str, re: &Regex, replacement: &str) -> String {
+        match toiletify_with_regex(word, re, replacement) {
+            Ok(new_word) => preserve_leading_case(word, &new_word),
+            Err(_error) => word.to_owned(),
+        }
+    }
+
+    /// Toiletifies every word in text, keeping all whitespace exactly as it
+    /// was, including runs of spaces, tabs and line breaks.
+    fn toiletify_preserving_whitespace(text: &str, re: &Regex, replacement: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut word_start: Option<usize> = None;
+
+        for (index, c) in text.char_indices() {
+            if c.is_whitespace() {
+                if let Some(start) = word_start.take() {
+                    result.push_str(&toiletify_sentence_word(
+                        &text[start..index],
+                        re,
+                        replacement,
+                    ));
+                }
+                result.push(c);
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+
+        if let Some(start) = word_start {
+            result.push_str(&toiletify_sentence_word(&text[start..], re, replacement));
+        }
+
+        result
+    }
+
+    /// Toiletifies text from a reader line by line and writes it to a writer.
+    ///
+    /// Only one line is held in memory at a time, so this works on files too
+    /// large to load at once. Line breaks and runs of whitespace between
+    /// words are written out exactly as they were read.
+    ///
+    /// # Arguments
+    ///
+    /// * 'reader' - Where to read the text from.
+    /// * 'writer' - Where to write the toiletified text to.
+    ///
+    /// # Returns
+    /// - Ok(()) once the reader is exhausted.
+    /// - The io::Error if reading or writing fails.
+    /// - An io::Error of kind ErrorKind::InvalidData if a line is not valid
+    ///   UTF-8. This stops the whole stream, and nothing from that line on
+    ///   is written.
+    ///
+    pub fn toiletify_reader<R: BufRead, W: Write>(mut reader: R, mut writer: W) -> io::Result<()> {
+        let mut line = String::new();
+
+        while reader.read_line(&mut line)? > 0 {
+            let new_line = toiletify_preserving_whitespace(&line, default_regex(), "toilet");
+            writer.write_all(new_line.as_bytes())?;
+            line.clear();
+        }
+
+        writer.flush()
+    }
+
+    /// Uppercases the first letter of new_word if original starts with an
+    /// uppercase letter. Otherwise new_word is returned as is.
+    fn preserve_leading_case(original: &str, new_word: &str) -> String {
+        let starts_upper = match original.chars().next() {
+            Some(first) => first.is_uppercase(),
+            None => false,
+        };
+
+        if !starts_upper {
+            return new_word.to_owned();
+        }
+
+        let mut chars = new_word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// The compiled bytes pattern, built on first use and shared after that.
+    fn default_bytes_regex() -> &'static BytesRegex {
+        static DEFAULT_BYTES_REGEX: OnceLock<BytesRegex> = OnceLock::new();
+        DEFAULT_BYTES_REGEX.get_or_init(|| {
+            BytesRegex::new(BYTES_TOILET_PATTERN).expect("BYTES_TOILET_PATTERN is valid")
+        })
+    }
+
+    /// Toiletifies one ASCII word taken from a byte buffer. Words that do
+    /// not transform are returned unchanged. Like toiletify_sentence, if the
+    /// word starts with an uppercase letter the result does too.
+    fn toiletify_ascii_word(word: &[u8]) -> Vec<u8> {
+        let mut new_word = default_bytes_regex()
+            .replace(word, &b"toilet"[..])
+            .into_owned();
+
+        if let (Some(first), Some(new_first)) = (word.first(), new_word.first_mut()) {
+            if first.is_ascii_uppercase() {
+                new_first.make_ascii_uppercase();
+            }
+        }
+
+        new_word
+    }
+
+    /// Same as toiletify_word, but works on raw ASCII bytes.
+    ///
+    /// This skips UTF-8 validation. Only ASCII bytes can be part of a match,
+    /// so any non-ASCII byte acts as a separator that breaks the pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * 'bytes' - The word as bytes, with no space (0x20).
+    ///
+    /// # Returns
+    /// - The transformed bytes if the word meets the conditions.
+    /// - Error::EmptyWord if there are no bytes.
+    /// - Error::WordHasSpace if the bytes contain a space.
+    /// - Error::NonToiletWord if the word does not meet the conditions.
+    /// - Error::InternalRegexError if the regex fails for some reason.
+    ///
+    pub fn toiletify_bytes(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        // Nothing to transform!
+        if bytes.is_empty() {
+            return Err(Error::EmptyWord);
+        }
+
+        // No words with spaces!
+        if bytes.contains(&b' ') {
+            return Err(Error::WordHasSpace);
+        }
+
+        let re = match BytesRegex::new(BYTES_TOILET_PATTERN) {
+            Ok(r_re) => r_re,
+            Err(r_error) => {
+                return Err(Error::InternalRegexError(r_error));
+            }
+        };
+
+        let new_bytes = re.replace(bytes, &b"toilet"[..]).into_owned();
+
+        if new_bytes == bytes {
+            Err(Error::NonToiletWord)
+        } else {
+            Ok(new_bytes)
+        }
+    }
+
+    /// Toiletifies every ASCII word in a byte buffer.
+    ///
+    /// A word is a run of ASCII letters and digits. Everything else, such as
+    /// spaces, line breaks, punctuation and non-ASCII bytes, stays exactly
+    /// where it was relative to the words around it.
+    ///
+    /// As in toiletify_sentence, a word that starts with an uppercase letter
+    /// keeps it, so Twilight becomes Toilet.
+    ///
+    /// # Arguments
+    ///
+    /// * 'buf' - The buffer to toiletify.
+    ///
+    pub fn toiletify_bytes_in_place(buf: &mut Vec<u8>) {
+        let mut result: Vec<u8> = Vec::with_capacity(buf.len());
+        let mut word_start: Option<usize> = None;
+
+        for (index, byte) in buf.iter().enumerate() {
+            if byte.is_ascii_alphanumeric() {
+                if word_start.is_none() {
+                    word_start = Some(index);
+                }
+            } else {
+                if let Some(start) = word_start.take() {
+                    result.extend_from_slice(&toiletify_ascii_word(&buf[start..index]));
+                }
+                result.push(*byte);
+            }
+        }
+
+        if let Some(start) = word_start {
+            result.extend_from_slice(&toiletify_ascii_word(&buf[start..]));
+        }
+
+        *buf = result;
+    }
+
     #[test]
     fn word_with_spaces_should_result_in_error() {
         let input: String = "Fun Times".to_owned();
@@ -127,4 +821,355 @@ pub mod zdm_toilet {
             }
         }
     }
+
+    #[test]
+    fn test_sentence_toiletifies_each_word() {
+        let input: String = "the twilight of totalitarian rule".to_owned();
+
+        assert_eq!(
+            toiletify_sentence(&input),
+            "the toilet of totoiletarian rule"
+        );
+    }
+
+    #[test]
+    fn test_word_with_custom_replacement() {
+        match toiletify_word_with("twilight", "potty") {
+            Ok(new_word) => assert_eq!(new_word, "potty"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn test_twilight_bytes_become_toilet() {
+        match toiletify_bytes(b"twilight") {
+            Ok(new_bytes) => assert_eq!(new_bytes, b"toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn non_ascii_bytes_break_the_match() {
+        let input: &[u8] = b"twi\xFFlight";
+
+        assert_eq!(toiletify_bytes(input), Err(Error::NonToiletWord));
+    }
+
+    #[test]
+    fn accented_letters_between_anchors_still_match() {
+        match toiletify_word("tîlît") {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn accented_anchor_only_matches_in_unicode_mode() {
+        assert_eq!(toiletify_word("ťwilight"), Err(Error::NonToiletWord));
+
+        match toiletify_word_unicode("ťwilight") {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn unicode_mode_matches_uppercase_accented_anchors() {
+        match toiletify_word_unicode("ŤWIŁIGHT") {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn test_toiletify_count() {
+        assert_eq!(toiletify_count("twilight"), 1);
+        assert_eq!(toiletify_count("twilighttwilight"), 2);
+        assert_eq!(toiletify_count("cat"), 0);
+
+        // Only the first match is replaced, and toilet replaces to itself.
+        assert_eq!(
+            toiletify_word("twilighttwilight"),
+            Ok("toilettwilight".to_owned())
+        );
+        assert_eq!(toiletify_count("toilet"), 1);
+        assert_eq!(toiletify_word("toilet"), Err(Error::NonToiletWord));
+    }
+
+    #[test]
+    fn test_sentence_keeps_leading_capital() {
+        let input: String = "Twilight falls".to_owned();
+
+        assert_eq!(toiletify_sentence(&input), "Toilet falls");
+    }
+
+    #[test]
+    fn empty_word_results_in_empty_word_error() {
+        assert_eq!(toiletify_word(""), Err(Error::EmptyWord));
+        assert_eq!(toiletify_bytes(b""), Err(Error::EmptyWord));
+    }
+
+    #[test]
+    fn whole_word_only_transforms_full_matches() {
+        match toiletify_whole_word("twilight") {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+
+        assert_eq!(
+            toiletify_whole_word("totalitarian"),
+            Err(Error::NonToiletWord)
+        );
+    }
+
+    #[test]
+    fn test_reader_keeps_lines_and_whitespace() {
+        let input = "the  twilight\tzone\nTwilight falls\n\nno match";
+        let mut output: Vec<u8> = Vec::new();
+
+        match toiletify_reader(io::Cursor::new(input), &mut output) {
+            Ok(()) => assert_eq!(
+                String::from_utf8(output).unwrap(),
+                "the  toilet\tzone\nToilet falls\n\nno match"
+            ),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_toiletified() {
+        match toiletify_word("totalitarian") {
+            Ok(new_word) => assert!(is_toiletified(&new_word)),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+
+        assert!(!is_toiletified("totalitarian"));
+    }
+
+    #[test]
+    fn test_sentence_whitespace_policies() {
+        let input: String = "the  twilight\tzone".to_owned();
+
+        assert_eq!(
+            toiletify_sentence_with(&input, "toilet", WhitespacePolicy::Collapse),
+            "the toilet zone"
+        );
+        assert_eq!(
+            toiletify_sentence_with(&input, "toilet", WhitespacePolicy::Preserve),
+            "the  toilet\tzone"
+        );
+    }
+
+    #[test]
+    fn test_toiletify_word_opt() {
+        assert_eq!(toiletify_word_opt("twilight"), Some("toilet".to_owned()));
+        assert_eq!(toiletify_word_opt("Lahabrea"), None);
+        assert_eq!(toiletify_word_opt("Fun Times"), None);
+    }
+
+    #[test]
+    fn test_reversible_round_trip() {
+        match toiletify_reversible("totalitarian") {
+            Ok((new_word, toilet_undo)) => {
+                assert_eq!(new_word, "totoiletarian");
+                assert_eq!(toilet_undo.original(), "talit");
+                assert_eq!(toilet_undo.position(), 2);
+                assert_eq!(undo(&new_word, &toilet_undo), "totalitarian");
+
+                // "toilet" is not at position 2 here, so nothing is undone.
+                assert_eq!(undo("toiletarian", &toilet_undo), "toiletarian");
+            }
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn test_token_keeps_punctuation() {
+        assert_eq!(toiletify_token("twilight,"), "toilet,");
+        assert_eq!(toiletify_token("(twilight)"), "(toilet)");
+        assert_eq!(toiletify_token("(cat)"), "(cat)");
+        assert_eq!(toiletify_token("..."), "...");
+    }
+
+    #[test]
+    fn test_bytes_in_place_keeps_separators() {
+        let mut buf: Vec<u8> = b"the twilight,\n  (totalitarian)\tcat\n".to_vec();
+
+        toiletify_bytes_in_place(&mut buf);
+
+        assert_eq!(buf, b"the toilet,\n  (totoiletarian)\tcat\n");
+    }
+
+    #[test]
+    fn toiletifying_twice_is_a_no_op() {
+        for input in ["twilight", "totalitarian", "teletypewriter", "tiletwilight"] {
+            match toiletify_word(input) {
+                Ok(new_word) => {
+                    assert_eq!(toiletify_word(&new_word), Err(Error::NonToiletWord))
+                }
+                Err(_err) => {
+                    panic!("Should not result in error!")
+                }
+            }
+        }
+
+        let once = toiletify_sentence("Twilight of totalitarian rule");
+        assert_eq!(toiletify_sentence(&once), once);
+    }
+
+    #[test]
+    fn word_longer_than_max_results_in_error() {
+        let config = ToiletConfig {
+            max_word_len: Some(8),
+            ..Default::default()
+        };
+
+        match toiletify_word_config("twilight", &config) {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+
+        assert_eq!(
+            toiletify_word_config("twilights", &config),
+            Err(Error::WordTooLong)
+        );
+    }
+
+    #[test]
+    fn test_toiletify_with_custom_regex() {
+        let re = Regex::new(r"[Cc][aeiou]t").unwrap();
+
+        match toiletify_with_regex("concatenate", &re, "dog") {
+            Ok(new_word) => assert_eq!(new_word, "condogenate"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+
+        assert_eq!(
+            toiletify_with_regex("twilight", &re, "dog"),
+            Err(Error::NonToiletWord)
+        );
+    }
+
+    #[test]
+    fn toilet_regex_matches_default_pattern() {
+        match toilet_regex() {
+            Ok(re) => {
+                assert_eq!(
+                    toiletify_with_regex("twilight", &re, "toilet"),
+                    toiletify_word("twilight")
+                )
+            }
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+    }
+
+    #[test]
+    fn test_corpus_counts_transformed_words() {
+        let corpus = ["twilight", "cat", "twilight", "totalitarian", "cat"];
+
+        let (new_words, counts) = toiletify_corpus(&corpus);
+
+        assert_eq!(
+            new_words,
+            ["toilet", "cat", "toilet", "totoiletarian", "cat"]
+        );
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts.get("twilight"), Some(&2));
+        assert_eq!(counts.get("totalitarian"), Some(&1));
+        assert_eq!(counts.get("cat"), None);
+    }
+
+    #[test]
+    fn uppercase_l_only_anchors_when_case_insensitive() {
+        let case_insensitive = ToiletConfig::default();
+        let case_sensitive = ToiletConfig {
+            case_sensitive_anchors: true,
+            ..Default::default()
+        };
+
+        match toiletify_word_config("twiLight", &case_insensitive) {
+            Ok(new_word) => assert_eq!(new_word, "toilet"),
+            Err(_err) => {
+                panic!("Should not result in error!")
+            }
+        }
+
+        assert_eq!(
+            toiletify_word_config("twiLight", &case_sensitive),
+            Err(Error::NonToiletWord)
+        );
+        assert_eq!(
+            toiletify_word_config("twilight", &case_sensitive),
+            Ok("toilet".to_owned())
+        );
+    }
+
+    #[test]
+    fn reader_stops_on_invalid_utf8() {
+        let input: &[u8] = b"twilight\n\xFF\n";
+        let mut output: Vec<u8> = Vec::new();
+
+        match toiletify_reader(input, &mut output) {
+            Ok(()) => panic!("Invalid UTF-8 should result in error!"),
+            Err(error) => assert_eq!(error.kind(), io::ErrorKind::InvalidData),
+        }
+    }
+
+    #[test]
+    fn unicode_mode_matches_extended_anchors() {
+        for input in ["ṭwilight", "twiḷight", "ƫwiɫight"] {
+            match toiletify_word_unicode(input) {
+                Ok(new_word) => assert_eq!(new_word, "toilet"),
+                Err(_err) => {
+                    panic!("{} should not result in error!", input)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_in_place_keeps_leading_capital_like_sentence() {
+        let mut buf: Vec<u8> = b"Twilight falls".to_vec();
+
+        toiletify_bytes_in_place(&mut buf);
+
+        assert_eq!(buf, b"Toilet falls");
+        assert_eq!(toiletify_sentence("Twilight falls"), "Toilet falls");
+    }
+
+    #[test]
+    fn collapse_drops_words_left_empty() {
+        assert_eq!(
+            toiletify_sentence_with("Twilight x", "", WhitespacePolicy::Collapse),
+            "x"
+        );
+        assert_eq!(
+            toiletify_sentence_with("a twilight b", "", WhitespacePolicy::Collapse),
+            "a b"
+        );
+    }
 }
@@ -0,0 +1,87 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use zdm_toilet::zdm_toilet::{toilet_regex, toiletify_sentence_with_regex, WhitespacePolicy};
+
+fn print_usage() {
+    eprintln!("Usage: zdm_toilet [--word <replacement>]");
+    eprintln!();
+    eprintln!("Reads lines from stdin and writes them toiletified to stdout.");
+    eprintln!("Whitespace inside a line collapses to single spaces, but line");
+    eprintln!("endings (LF or CRLF) are written out exactly as they were read.");
+}
+
+/// Splits a line read with read_line into its text and its line ending.
+fn split_line_ending(line: &str) -> (&str, &str) {
+    if let Some(text) = line.strip_suffix("\r\n") {
+        (text, "\r\n")
+    } else if let Some(text) = line.strip_suffix('\n') {
+        (text, "\n")
+    } else {
+        (line, "")
+    }
+}
+
+fn main() {
+    let mut replacement = String::from("toilet");
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--word" => match args.next() {
+                Some(r_word) => replacement = r_word,
+                None => {
+                    eprintln!("--word needs a replacement.");
+                    print_usage();
+                    process::exit(2);
+                }
+            },
+            "-h" | "--help" => {
+                print_usage();
+                return;
+            }
+            _ => {
+                eprintln!("Unknown argument: {}", arg);
+                print_usage();
+                process::exit(2);
+            }
+        }
+    }
+
+    let re = match toilet_regex() {
+        Ok(r_re) => r_re,
+        Err(r_error) => {
+            eprintln!("Failed to build the toilet regex: {:?}", r_error);
+            process::exit(1);
+        }
+    };
+
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        match input.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(r_error) => {
+                eprintln!("Failed to read stdin: {}", r_error);
+                process::exit(1);
+            }
+        }
+
+        let (text, ending) = split_line_ending(&line);
+        let new_text =
+            toiletify_sentence_with_regex(text, &re, &replacement, WhitespacePolicy::Collapse);
+
+        if let Err(r_error) = write!(out, "{}{}", new_text, ending) {
+            eprintln!("Failed to write stdout: {}", r_error);
+            process::exit(1);
+        }
+    }
+}
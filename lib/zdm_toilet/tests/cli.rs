@@ -0,0 +1,46 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_cli(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zdm_toilet"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start zdm_toilet");
+
+    child
+        .stdin
+        .take()
+        .expect("stdin should be piped")
+        .write_all(input.as_bytes())
+        .expect("Failed to write stdin");
+
+    let output = child
+        .wait_with_output()
+        .expect("Failed to wait on zdm_toilet");
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).expect("Output should be UTF-8")
+}
+
+#[test]
+fn cli_toiletifies_each_line() {
+    let output = run_cli(&[], "the twilight zone\ncat\n");
+
+    assert_eq!(output, "the toilet zone\ncat\n");
+}
+
+#[test]
+fn cli_uses_word_flag_as_replacement() {
+    let output = run_cli(&["--word", "potty"], "twilight\n");
+
+    assert_eq!(output, "potty\n");
+}
+
+#[test]
+fn cli_passes_line_endings_through() {
+    let output = run_cli(&[], "the twilight zone\r\ncat\r\ntwilight");
+
+    assert_eq!(output, "the toilet zone\r\ncat\r\ntoilet");
+}